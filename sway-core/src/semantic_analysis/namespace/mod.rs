@@ -0,0 +1,17 @@
+mod module;
+mod namespace;
+mod root;
+mod submodule_namespace;
+
+pub use module::Module;
+pub use namespace::{Namespace, NamespaceKind, TryInsertingTraitImplOnFailure};
+pub use root::{ModuleId, Root};
+pub use submodule_namespace::SubmoduleNamespace;
+
+use crate::Ident;
+
+/// A non-owning module path, analogous to how `&[T]` relates to `Vec<T>`.
+pub type Path = [Ident];
+
+/// An absolute or relative module path made up of a sequence of [Ident]s.
+pub type PathBuf = Vec<Ident>;
@@ -0,0 +1,385 @@
+use std::{cell::Cell, collections::HashMap};
+
+use sway_error::{
+    error::CompileError,
+    handler::{ErrorEmitted, Handler},
+    warning::{CompileWarning, Warning},
+};
+use sway_types::{span::Span, Spanned};
+
+use crate::{
+    language::{ty, Visibility},
+    Ident,
+};
+
+use super::{namespace::NamespaceKind, root::ModuleId};
+
+/// A declaration bound to an [Ident] within a [Module].
+///
+/// Items declared directly in the module are never "unused"; explicit `use` imports carry a
+/// `used` flag, set by [Module::resolve_symbol] whenever the binding actually satisfies a
+/// lookup, so that [Module::check_unused_imports] can warn about the ones that never did.
+#[derive(Clone, Debug)]
+enum Binding {
+    Item(ty::TyDecl),
+    Import {
+        decl: ty::TyDecl,
+        span: Span,
+        used: Cell<bool>,
+    },
+}
+
+impl Binding {
+    fn decl(&self) -> &ty::TyDecl {
+        match self {
+            Binding::Item(decl) | Binding::Import { decl, .. } => decl,
+        }
+    }
+
+    fn mark_used(&self) {
+        if let Binding::Import { used, .. } = self {
+            used.set(true);
+        }
+    }
+}
+
+/// A binding brought in by a glob import (`use foo::*;`).
+///
+/// Unlike [Binding::Import], more than one of these may legitimately exist for the same
+/// [Ident]: two globs that happen to supply the same name only conflict if they disagree on the
+/// declaration (see [Module::resolve_symbol]).
+#[derive(Clone, Debug)]
+struct GlobBinding {
+    decl: ty::TyDecl,
+    span: Span,
+    used: Cell<bool>,
+}
+
+/// A single module's contents: the items and imports visible within it.
+///
+/// Bindings are stored in two parallel tables, one per [NamespaceKind], following the
+/// `TypeNS`/`ValueNS` split used by rustc's resolver. A single [Ident] may be bound in both
+/// tables at once (e.g. a unit-like struct usable both as a type and as a constructor value);
+/// only a collision within the *same* table is a redefinition.
+#[derive(Clone, Debug)]
+pub struct Module {
+    pub(crate) name: Option<Ident>,
+    pub(crate) span: Option<Span>,
+    pub(crate) visibility: Visibility,
+    pub(crate) is_external: bool,
+    /// Direct submodules of this module, keyed by name and addressed via [ModuleId] into the
+    /// root's arena rather than owned directly (see [super::Root]).
+    pub(crate) submodules: HashMap<String, ModuleId>,
+    /// Bindings in the type namespace: structs, enums, traits, type aliases, modules-as-types.
+    type_symbols: HashMap<Ident, Binding>,
+    /// Bindings in the value namespace: functions, constants, unit-struct constructors.
+    value_symbols: HashMap<Ident, Binding>,
+    /// Glob-imported (`use foo::*;`) bindings in the type namespace. Consulted only when
+    /// `type_symbols` has no explicit binding for the identifier (see [Module::resolve_symbol]).
+    type_glob_symbols: HashMap<Ident, Vec<GlobBinding>>,
+    /// Glob-imported (`use foo::*;`) bindings in the value namespace.
+    value_glob_symbols: HashMap<Ident, Vec<GlobBinding>>,
+    /// Set by [Module::check_unused_imports] the first time it runs for this module, so that a
+    /// multi-pass compiler driver re-entering the same submodule (and therefore the same
+    /// [ModuleId], since submodules are never re-allocated — see [super::Root::get_or_alloc_submodule])
+    /// doesn't emit the same unused-import warnings again on every later pass. Whether a given
+    /// pass finalizes at all is decided by the caller (see `finalize_unused_imports` on
+    /// [super::Namespace::enter_submodule]); this flag only stops repeats.
+    finalized: Cell<bool>,
+}
+
+impl Module {
+    /// Creates a fresh, empty module with no items, imports, or submodules.
+    pub(crate) fn new(
+        name: Option<Ident>,
+        span: Option<Span>,
+        visibility: Visibility,
+        is_external: bool,
+    ) -> Self {
+        Module {
+            name,
+            span,
+            visibility,
+            is_external,
+            submodules: HashMap::new(),
+            type_symbols: HashMap::new(),
+            value_symbols: HashMap::new(),
+            type_glob_symbols: HashMap::new(),
+            value_glob_symbols: HashMap::new(),
+            finalized: Cell::new(false),
+        }
+    }
+
+    fn table(&self, namespace_kind: NamespaceKind) -> &HashMap<Ident, Binding> {
+        match namespace_kind {
+            NamespaceKind::Type => &self.type_symbols,
+            NamespaceKind::Value => &self.value_symbols,
+        }
+    }
+
+    fn table_mut(&mut self, namespace_kind: NamespaceKind) -> &mut HashMap<Ident, Binding> {
+        match namespace_kind {
+            NamespaceKind::Type => &mut self.type_symbols,
+            NamespaceKind::Value => &mut self.value_symbols,
+        }
+    }
+
+    fn glob_table(&self, namespace_kind: NamespaceKind) -> &HashMap<Ident, Vec<GlobBinding>> {
+        match namespace_kind {
+            NamespaceKind::Type => &self.type_glob_symbols,
+            NamespaceKind::Value => &self.value_glob_symbols,
+        }
+    }
+
+    fn glob_table_mut(
+        &mut self,
+        namespace_kind: NamespaceKind,
+    ) -> &mut HashMap<Ident, Vec<GlobBinding>> {
+        match namespace_kind {
+            NamespaceKind::Type => &mut self.type_glob_symbols,
+            NamespaceKind::Value => &mut self.value_glob_symbols,
+        }
+    }
+
+    fn insert_binding(
+        &mut self,
+        handler: &Handler,
+        symbol: Ident,
+        namespace_kind: NamespaceKind,
+        binding: Binding,
+    ) -> Result<(), ErrorEmitted> {
+        if let Some(existing) = self.table(namespace_kind).get(&symbol) {
+            return Err(handler.emit_err(CompileError::MultipleDefinitionsOfName {
+                name: symbol.clone(),
+                previous_span: existing.decl().span(),
+                span: symbol.span(),
+            }));
+        }
+        self.table_mut(namespace_kind).insert(symbol, binding);
+        Ok(())
+    }
+
+    /// Declares `symbol` as `decl` in the given namespace.
+    ///
+    /// Only a collision within the same [NamespaceKind] is treated as a redefinition; the same
+    /// `symbol` may already be present in the *other* namespace without conflict.
+    pub(crate) fn insert_symbol(
+        &mut self,
+        handler: &Handler,
+        symbol: Ident,
+        namespace_kind: NamespaceKind,
+        decl: ty::TyDecl,
+    ) -> Result<(), ErrorEmitted> {
+        self.insert_binding(handler, symbol, namespace_kind, Binding::Item(decl))
+    }
+
+    /// Declares `symbol` as brought into scope by an explicit `use` item, so that it can be
+    /// reported as unused (see [Module::check_unused_imports]) if nothing ever resolves through
+    /// it.
+    pub(crate) fn insert_imported_symbol(
+        &mut self,
+        handler: &Handler,
+        symbol: Ident,
+        namespace_kind: NamespaceKind,
+        decl: ty::TyDecl,
+        import_span: Span,
+    ) -> Result<(), ErrorEmitted> {
+        self.insert_binding(
+            handler,
+            symbol,
+            namespace_kind,
+            Binding::Import {
+                decl,
+                span: import_span,
+                used: Cell::new(false),
+            },
+        )
+    }
+
+    /// Declares `symbol` as brought into scope by a glob import (`use foo::*;`).
+    ///
+    /// Unlike [Module::insert_symbol]/[Module::insert_imported_symbol], this never errors on its
+    /// own: two globs that disagree on the same identifier are only a problem if something
+    /// actually looks that identifier up (see [Module::resolve_symbol]), since an unused
+    /// ambiguous name is harmless.
+    pub(crate) fn insert_glob_symbol(
+        &mut self,
+        symbol: Ident,
+        namespace_kind: NamespaceKind,
+        decl: ty::TyDecl,
+        import_span: Span,
+    ) {
+        self.glob_table_mut(namespace_kind)
+            .entry(symbol)
+            .or_default()
+            .push(GlobBinding {
+                decl,
+                span: import_span,
+                used: Cell::new(false),
+            });
+    }
+
+    /// Resolves `symbol` in the given namespace.
+    ///
+    /// Explicit items and explicit imports always take precedence: glob-imported bindings are
+    /// only consulted when `symbol` has no explicit binding. If more than one glob import
+    /// supplies `symbol` with a different declaration, resolution fails with an ambiguity error
+    /// naming both imports; globs that agree on the same declaration collapse silently.
+    ///
+    /// Marks whichever binding(s) satisfied the lookup as used, for the unused-import lint.
+    pub(crate) fn resolve_symbol(
+        &self,
+        handler: &Handler,
+        symbol: &Ident,
+        namespace_kind: NamespaceKind,
+    ) -> Result<ty::TyDecl, ErrorEmitted> {
+        if let Some(binding) = self.table(namespace_kind).get(symbol) {
+            binding.mark_used();
+            return Ok(binding.decl().clone());
+        }
+
+        let Some(candidates) = self.glob_table(namespace_kind).get(symbol) else {
+            return Err(handler.emit_err(CompileError::SymbolNotFound {
+                name: symbol.clone(),
+                span: symbol.span(),
+            }));
+        };
+        let first = candidates
+            .first()
+            .expect("glob_table never stores an empty Vec");
+
+        // Plain `!=` is enough here, with no `Engines` involved: each `decl` is already a fully
+        // resolved `ty::TyDecl` by the time it's bound into `glob_table`, so two candidates
+        // disagree iff they name different declaration ids — nothing the type/decl engines would
+        // need to be consulted to tell apart.
+        if let Some(disagreement) = candidates.iter().find(|c| c.decl != first.decl) {
+            // The lookup did reach these imports, even though it couldn't pick between them;
+            // mark them used so the ambiguity error isn't followed by spurious unused-import
+            // warnings for the very imports that caused it.
+            for candidate in candidates {
+                candidate.used.set(true);
+            }
+            return Err(handler.emit_err(CompileError::AmbiguousGlobImport {
+                name: symbol.clone(),
+                first_span: first.span.clone(),
+                second_span: disagreement.span.clone(),
+            }));
+        }
+
+        for candidate in candidates {
+            candidate.used.set(true);
+        }
+        Ok(first.decl.clone())
+    }
+
+    /// Emits an unused-import warning for every explicit or glob `use` import in this module
+    /// that was never marked used by [Module::resolve_symbol].
+    ///
+    /// A no-op on every call after the first for a given module: submodules keep the same
+    /// [ModuleId] (and therefore the same `used` flags) across however many passes a driver
+    /// makes over the tree, so without this guard every later finalizing call would report the
+    /// still-unused imports again.
+    pub(crate) fn check_unused_imports(&self, handler: &Handler) {
+        if self.finalized.replace(true) {
+            return;
+        }
+        for table in [&self.type_symbols, &self.value_symbols] {
+            for binding in table.values() {
+                if let Binding::Import { span, used, .. } = binding {
+                    if !used.get() {
+                        handler.emit_warn(CompileWarning {
+                            span: span.clone(),
+                            warning_content: Warning::UnusedImport,
+                        });
+                    }
+                }
+            }
+        }
+        for table in [&self.type_glob_symbols, &self.value_glob_symbols] {
+            for candidates in table.values() {
+                for candidate in candidates {
+                    if !candidate.used.get() {
+                        handler.emit_warn(CompileWarning {
+                            span: candidate.span.clone(),
+                            warning_content: Warning::UnusedImport,
+                        });
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // `ty::TyDecl` isn't cheap to construct in isolation (a real one is produced by the decl
+    // engine during type-checking), so tests stand in with `ErrorRecovery`, which needs nothing
+    // but a `Span` and still participates in equality/inequality like any other variant. Two
+    // `ErrorRecovery`s compare equal iff their spans do, so distinguishing decls for the
+    // ambiguity test means building each one's span over its own name text (an
+    // `Ident::new_no_span` would not do: its span is the same dummy span whatever the name).
+    fn decl_named(name: &str) -> ty::TyDecl {
+        ty::TyDecl::ErrorRecovery(Span::from_string(name.to_string()))
+    }
+
+    fn ident(name: &str) -> Ident {
+        Ident::new_no_span(name.to_string())
+    }
+
+    #[test]
+    fn explicit_binding_takes_precedence_over_glob() {
+        let mut module = Module::new(None, None, Visibility::Public, false);
+        let name = ident("Foo");
+
+        let explicit_decl = decl_named("explicit");
+        module
+            .insert_symbol(
+                &Handler::default(),
+                name.clone(),
+                NamespaceKind::Type,
+                explicit_decl.clone(),
+            )
+            .unwrap();
+        // A glob import disagreeing with the explicit binding must not matter: explicit bindings
+        // are never even compared against glob candidates.
+        module.insert_glob_symbol(
+            name.clone(),
+            NamespaceKind::Type,
+            decl_named("glob"),
+            Span::dummy(),
+        );
+
+        let handler = Handler::default();
+        let resolved = module
+            .resolve_symbol(&handler, &name, NamespaceKind::Type)
+            .unwrap();
+        assert!(handler.consume().0.is_empty());
+        assert_eq!(resolved, explicit_decl);
+    }
+
+    #[test]
+    fn disagreeing_glob_imports_are_ambiguous() {
+        let mut module = Module::new(None, None, Visibility::Public, false);
+        let name = ident("Bar");
+
+        module.insert_glob_symbol(
+            name.clone(),
+            NamespaceKind::Value,
+            decl_named("first"),
+            Span::dummy(),
+        );
+        module.insert_glob_symbol(
+            name.clone(),
+            NamespaceKind::Value,
+            // A differently-named decl makes this a distinct `TyDecl`, so the two globs disagree.
+            decl_named("second"),
+            Span::dummy(),
+        );
+
+        let handler = Handler::default();
+        let result = module.resolve_symbol(&handler, &name, NamespaceKind::Value);
+        assert!(result.is_err());
+    }
+}
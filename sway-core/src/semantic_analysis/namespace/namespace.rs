@@ -1,11 +1,19 @@
 use crate::{
     language::{ty, CallPath, Visibility},
-    Engines, Ident, TypeId,
+    Ident, TypeId,
 };
 
-use super::{module::Module, root::Root, submodule_namespace::SubmoduleNamespace, Path, PathBuf};
+use super::{
+    module::Module,
+    root::{ModuleId, Root},
+    submodule_namespace::SubmoduleNamespace,
+    Path, PathBuf,
+};
 
-use sway_error::handler::{ErrorEmitted, Handler};
+use sway_error::{
+    handler::{ErrorEmitted, Handler},
+    warning::{CompileWarning, Warning},
+};
 use sway_types::span::Span;
 
 /// Enum used to pass a value asking for insertion of type into trait map when an implementation
@@ -16,7 +24,25 @@ pub enum TryInsertingTraitImplOnFailure {
     No,
 }
 
+/// The namespace a symbol is being looked up in: the type namespace or the value namespace.
+///
+/// A single [Ident] may legitimately be bound in both namespaces at once, e.g. a unit-like
+/// struct `S` that is usable both as a type and as a constructor value, or a module and a
+/// function sharing a name. Resolution is only ever ambiguous, and only ever an error, when two
+/// bindings collide within the *same* namespace.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum NamespaceKind {
+    Type,
+    Value,
+}
+
 /// The set of items that represent the namespace context passed throughout type checking.
+///
+/// [Module]s are allocated once into the `root`'s arena and referenced from elsewhere by
+/// [ModuleId], so entering a submodule (see [Namespace::enter_submodule]) no longer deep-clones
+/// the module subtree rooted there. `Namespace` itself still implements `Clone` for callers that
+/// snapshot one (e.g. before type-checking diverging branches); that clone now only has to copy
+/// the arena once, rather than paying a clone on every submodule visit.
 #[derive(Clone, Debug)]
 pub struct Namespace {
     /// An immutable namespace that consists of the names that should always be present, no matter
@@ -80,7 +106,7 @@ impl Namespace {
     /// Note that the [Namespace] will automatically dereference to this [Module] when attempting
     /// to call any [Module] methods.
     pub fn module(&self) -> &Module {
-        &self.root.module[&self.mod_path]
+        self.root.module(&self.mod_path)
     }
 
     /// Mutable access to the current [Module], i.e. the module at the inner `mod_path`.
@@ -88,31 +114,58 @@ impl Namespace {
     /// Note that the [Namespace] will automatically dereference to this [Module] when attempting
     /// to call any [Module] methods.
     pub fn module_mut(&mut self) -> &mut Module {
-        &mut self.root.module[&self.mod_path]
+        self.root.module_mut(&self.mod_path)
     }
 
     /// Short-hand for calling [Root::resolve_symbol] on `root` with the `mod_path`.
+    ///
+    /// `namespace_kind` tells the resolver whether `symbol` is expected to name a type (e.g. a
+    /// path appearing in type position) or a value (e.g. a path appearing in an expression), so
+    /// that a name bound in both namespaces resolves to the binding the caller actually wants.
+    ///
+    /// Explicit local items and explicit imports always take precedence over glob imports
+    /// (`use foo::*`): a glob-imported binding is only considered when no explicit binding for
+    /// `symbol` exists. If more than one glob import supplies `symbol` with differing
+    /// declarations, resolution fails with an ambiguity error pointing at both imports; glob
+    /// imports that agree on the same declaration collapse silently.
     pub(crate) fn resolve_symbol(
         &self,
         handler: &Handler,
-        engines: &Engines,
         symbol: &Ident,
+        namespace_kind: NamespaceKind,
         self_type: Option<TypeId>,
     ) -> Result<ty::TyDecl, ErrorEmitted> {
         self.root
-            .resolve_symbol(handler, engines, &self.mod_path, symbol, self_type)
+            .resolve_symbol(handler, &self.mod_path, symbol, namespace_kind, self_type)
     }
 
     /// Short-hand for calling [Root::resolve_call_path] on `root` with the `mod_path`.
+    ///
+    /// See [Namespace::resolve_symbol] for the meaning of `namespace_kind` and for how explicit
+    /// imports are preferred over glob imports when resolving the call path's final segment.
     pub(crate) fn resolve_call_path(
         &self,
         handler: &Handler,
-        engines: &Engines,
         call_path: &CallPath,
+        namespace_kind: NamespaceKind,
         self_type: Option<TypeId>,
     ) -> Result<ty::TyDecl, ErrorEmitted> {
-        self.root
-            .resolve_call_path(handler, engines, &self.mod_path, call_path, self_type)
+        self.root.resolve_call_path(
+            handler,
+            &self.mod_path,
+            call_path,
+            namespace_kind,
+            self_type,
+        )
+    }
+
+    /// Emits an unused-import warning for every `use` item in the current module that was never
+    /// hit by [Namespace::resolve_symbol] or [Namespace::resolve_call_path].
+    ///
+    /// Called by [SubmoduleNamespace]'s `Drop` impl once the submodule is fully type-checked, so
+    /// that every import has had its chance to be used by the time we check.
+    pub(crate) fn check_unused_imports(&self, handler: &Handler) {
+        self.module().check_unused_imports(handler);
     }
 
     /// "Enter" the submodule at the given path by returning a new [SubmoduleNamespace].
@@ -120,15 +173,29 @@ impl Namespace {
     /// Here we temporarily change `mod_path` to the given `dep_mod_path` and wrap `self` in a
     /// [SubmoduleNamespace] type. When dropped, the [SubmoduleNamespace] resets the `mod_path`
     /// back to the original path so that we can continue type-checking the current module after
-    /// finishing with the dependency.
+    /// finishing with the dependency, and, if `finalize_unused_imports` is set, emits the
+    /// submodule's unused-import warnings (via `handler`).
+    ///
+    /// `finalize_unused_imports` should be `true` only for the pass that does the real,
+    /// import-resolving type-check of the tree. A compiler driver that makes an earlier
+    /// collection-only pass over the same submodules (which share their [ModuleId], and
+    /// therefore their imports' `used` flags, across every pass — see
+    /// [super::Root::get_or_alloc_submodule]) must pass `false` for that pass, or every import
+    /// would be reported unused before anything had a chance to resolve through it.
     pub(crate) fn enter_submodule(
         &mut self,
+        handler: &Handler,
         mod_name: Ident,
         visibility: Visibility,
         module_span: Span,
+        finalize_unused_imports: bool,
     ) -> SubmoduleNamespace {
-        let init = self.init.clone();
-        self.submodules.entry(mod_name.to_string()).or_insert(init);
+        // `init` is only cloned here, lazily, to seed a freshly-allocated arena slot the first
+        // time this submodule is entered; subsequent visits reuse the existing `ModuleId`
+        // instead of cloning the subtree again.
+        let _: ModuleId =
+            self.root
+                .get_or_alloc_submodule(&self.mod_path, &mod_name, || self.init.clone());
         let submod_path: Vec<_> = self
             .mod_path
             .iter()
@@ -143,6 +210,8 @@ impl Namespace {
         SubmoduleNamespace {
             namespace: self,
             parent_mod_path,
+            handler: handler.clone(),
+            finalize_unused_imports,
         }
     }
 
@@ -195,6 +264,93 @@ impl Namespace {
         }
     }
 
+    /// Checks whether the qualifying prefix on `call_path` is unnecessary, and if so, exactly
+    /// how much of it is.
+    ///
+    /// For each shorter prefix length, from one segment fewer than the full prefix down to none
+    /// (the full prefix itself is not re-resolved: that is what produced `resolved`), this re-resolves
+    /// `call_path.suffix` from the module reached by keeping only that many *trailing* prefix
+    /// segments (i.e. dropping the corresponding number of leading segments). Prefix lengths
+    /// that don't correspond to a module actually entered during type-checking (as opposed to
+    /// one that was entered but simply doesn't redeclare `call_path.suffix`) are treated the same
+    /// as a failed resolution: not redundant, stop trying shorter prefixes. The longest leading
+    /// run that can be dropped while still resolving to the exact same declaration as `resolved`
+    /// is reported as superfluous, so e.g. in `a::b::Baz` only `a::` is flagged when `b::Baz`
+    /// alone would still resolve correctly but `Baz` alone would not.
+    ///
+    /// `resolved` must be the [ty::TyDecl] that `call_path` already resolved to via
+    /// [Namespace::resolve_call_path], so this check re-resolves in the identical context rather
+    /// than risking a coincidental match against some other, differently-reached declaration.
+    pub(crate) fn check_unnecessary_qualification(
+        &self,
+        handler: &Handler,
+        call_path: &CallPath,
+        namespace_kind: NamespaceKind,
+        // Trial resolution below goes through `Module::resolve_symbol`, which (like the rest of
+        // `Module`'s table lookups) isn't `self_type`-aware, so this has no effect yet; kept so
+        // the signature doesn't have to change again once module-level resolution does take
+        // `Self` into account.
+        _self_type: Option<TypeId>,
+        resolved: &ty::TyDecl,
+    ) {
+        if call_path.prefixes.is_empty() {
+            // Nothing is being qualified away.
+            return;
+        }
+
+        // `drop_count` segments can be dropped from the front of `call_path.prefixes` once this
+        // loop finishes; it only grows while each successively-shorter trailing slice still
+        // resolves to `resolved`, so the search stops at the first prefix length that doesn't.
+        let mut drop_count = 0;
+        for candidate_drop_count in 1..=call_path.prefixes.len() {
+            let trial_mod_path: PathBuf = self
+                .mod_path
+                .iter()
+                .cloned()
+                .chain(call_path.prefixes[candidate_drop_count..].iter().cloned())
+                .collect();
+            // Only the full `mod_path ++ prefixes` path is guaranteed to be registered in the
+            // root's arena; a trimmed trailing slice may not name a module that was ever
+            // entered. That's not an error here, just proof that this much can't be dropped, so
+            // look it up with `try_module` rather than the panicking `Root::module`.
+            let Some(trial_module) = self.root.try_module(&trial_mod_path) else {
+                break;
+            };
+            let trial_handler = Handler::default();
+            let trial =
+                trial_module.resolve_symbol(&trial_handler, &call_path.suffix, namespace_kind);
+            match trial {
+                // `ty::TyDecl` equality identifies the same declaration (e.g. by declaration
+                // id), not mere structural similarity, so this can't match a different item that
+                // happens to look the same.
+                Ok(decl) if &decl == resolved => drop_count = candidate_drop_count,
+                _ => break,
+            }
+        }
+
+        if drop_count == 0 {
+            return;
+        }
+
+        let redundant_prefixes = &call_path.prefixes[..drop_count];
+        let redundant_span = redundant_prefixes
+            .first()
+            .expect("drop_count > 0")
+            .span()
+            .join(
+                redundant_prefixes
+                    .last()
+                    .expect("drop_count > 0")
+                    .span(),
+            );
+        handler.emit_warn(CompileWarning {
+            span: redundant_span,
+            warning_content: Warning::UnnecessaryQualification {
+                call_path: call_path.clone(),
+            },
+        });
+    }
+
     /// Returns true if the module given by the `absolute_module_path` is external
     /// to the current package. External modules are imported in the `Forc.toml` file.
     pub(crate) fn module_is_external(&self, absolute_module_path: &Path) -> bool {
@@ -221,3 +377,115 @@ impl std::ops::DerefMut for Namespace {
         self.module_mut()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use sway_types::Spanned;
+
+    fn ident(name: &str) -> Ident {
+        Ident::new_no_span(name.to_string())
+    }
+
+    // See module.rs's test module for why `ErrorRecovery` stands in for a real, engine-produced
+    // `TyDecl` here: it needs nothing but a `Span`, and two `ErrorRecovery`s compare equal iff
+    // their spans do.
+    fn decl_named(name: &str) -> ty::TyDecl {
+        ty::TyDecl::ErrorRecovery(ident(name).span())
+    }
+
+    fn call_path(prefixes: &[&str], suffix: &str) -> CallPath {
+        CallPath {
+            prefixes: prefixes.iter().map(|p| ident(p)).collect(),
+            suffix: ident(suffix),
+            is_absolute: false,
+        }
+    }
+
+    /// Builds a namespace rooted at package `pkg`, with an empty submodule chain `a` -> `a::b`
+    /// already registered (as if both had been entered once via `enter_submodule`), and `Baz`
+    /// declared in the type namespace of `a::b`.
+    fn namespace_with_nested_baz() -> (Namespace, ty::TyDecl) {
+        let root_module = Module::new(Some(ident("pkg")), None, Visibility::Public, false);
+        let mut namespace = Namespace::init_root(root_module);
+        namespace.root.name = Some(ident("pkg"));
+
+        namespace.root.get_or_alloc_submodule(&[], &ident("a"), || {
+            Module::new(Some(ident("a")), None, Visibility::Public, false)
+        });
+        namespace
+            .root
+            .get_or_alloc_submodule(&[ident("a")], &ident("b"), || {
+                Module::new(Some(ident("b")), None, Visibility::Public, false)
+            });
+
+        let baz = decl_named("Baz");
+        namespace
+            .root
+            .module_mut(&[ident("a"), ident("b")])
+            .insert_symbol(
+                &Handler::default(),
+                ident("Baz"),
+                NamespaceKind::Type,
+                baz.clone(),
+            )
+            .unwrap();
+
+        (namespace, baz)
+    }
+
+    #[test]
+    fn unnecessary_qualification_flags_only_the_minimal_redundant_prefix() {
+        let (mut namespace, baz) = namespace_with_nested_baz();
+        // A root-level module `b` that imports the very same `Baz` declared in `a::b`.
+        namespace.root.get_or_alloc_submodule(&[], &ident("b"), || {
+            Module::new(Some(ident("b")), None, Visibility::Public, false)
+        });
+        namespace
+            .root
+            .module_mut(&[ident("b")])
+            .insert_imported_symbol(
+                &Handler::default(),
+                ident("Baz"),
+                NamespaceKind::Type,
+                baz.clone(),
+                Span::dummy(),
+            )
+            .unwrap();
+        // As if we're type-checking the root module, and someone wrote the (partially
+        // redundant) path `a::b::Baz`: only the `a::` prefix is superfluous, since `b::Baz`
+        // alone still resolves to the same `Baz` from here but `Baz` alone would not.
+        let path = call_path(&["a", "b"], "Baz");
+
+        let handler = Handler::default();
+        namespace.check_unnecessary_qualification(
+            &handler,
+            &path,
+            NamespaceKind::Type,
+            None,
+            &baz,
+        );
+
+        assert_eq!(handler.consume().1.len(), 1, "expected exactly one unnecessary-qualification warning");
+    }
+
+    #[test]
+    fn unnecessary_qualification_does_not_panic_on_an_unregistered_trial_path() {
+        let (namespace, baz) = namespace_with_nested_baz();
+        // From the root, trimming the leading segment of `a::b::Baz` tries the path `b` (not
+        // `a::b`), which was never registered in the arena. This used to panic inside
+        // `Root::resolve_symbol`; it must now just mean "not redundant."
+        let path = call_path(&["a", "b"], "Baz");
+
+        let handler = Handler::default();
+        namespace.check_unnecessary_qualification(
+            &handler,
+            &path,
+            NamespaceKind::Type,
+            None,
+            &baz,
+        );
+
+        assert!(handler.consume().1.is_empty());
+    }
+}
@@ -0,0 +1,171 @@
+use std::collections::HashMap;
+
+use sway_error::{
+    error::CompileError,
+    handler::{ErrorEmitted, Handler},
+};
+use sway_types::Spanned;
+
+use crate::{language::ty, Ident, TypeId};
+
+use super::{module::Module, namespace::NamespaceKind, Path, PathBuf};
+
+/// A lightweight index into [Root]'s module arena.
+///
+/// Modules are allocated once, here, rather than being owned (and therefore deep-cloned on
+/// every visit) by their parent; everywhere else a module is referred to, it's by `ModuleId`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ModuleId(usize);
+
+/// The root of a package's namespace, and the sole owner of every [Module] in the tree.
+///
+/// [Module]s are allocated once into `arena` and addressed by [ModuleId] from then on, so
+/// entering a submodule (see [super::Namespace::enter_submodule]) never has to deep-clone the
+/// subtree rooted there.
+#[derive(Clone, Debug)]
+pub struct Root {
+    pub(crate) name: Option<Ident>,
+    arena: Vec<Module>,
+    path_to_id: HashMap<PathBuf, ModuleId>,
+}
+
+impl From<Module> for Root {
+    fn from(root_module: Module) -> Self {
+        let mut root = Root {
+            name: None,
+            arena: Vec::new(),
+            path_to_id: HashMap::new(),
+        };
+        let id = root.alloc_module(root_module);
+        root.path_to_id.insert(PathBuf::new(), id);
+        root
+    }
+}
+
+impl Root {
+    fn alloc_module(&mut self, module: Module) -> ModuleId {
+        let id = ModuleId(self.arena.len());
+        self.arena.push(module);
+        id
+    }
+
+    /// Looks up the [ModuleId] registered at `path`, if any.
+    ///
+    /// This is the fallible primitive: callers with a path they *know* is registered (because
+    /// they derived it from `mod_path`/`enter_submodule`) should go through [Root::id_of]
+    /// instead; callers probing a path that may or may not exist (e.g. a speculative trial
+    /// path in a lint) must use this and handle `None`, rather than letting a missing path
+    /// panic.
+    fn try_id_of(&self, path: &Path) -> Option<ModuleId> {
+        self.path_to_id.get(path).copied()
+    }
+
+    fn id_of(&self, path: &Path) -> ModuleId {
+        self.try_id_of(path)
+            .unwrap_or_else(|| panic!("no module registered at path {path:?}"))
+    }
+
+    pub(crate) fn module(&self, path: &Path) -> &Module {
+        &self.arena[self.id_of(path).0]
+    }
+
+    pub(crate) fn module_mut(&mut self, path: &Path) -> &mut Module {
+        let id = self.id_of(path);
+        &mut self.arena[id.0]
+    }
+
+    /// Like [Root::module], but returns `None` instead of panicking when `path` isn't a
+    /// registered module, for callers that probe a path which may not exist.
+    pub(crate) fn try_module(&self, path: &Path) -> Option<&Module> {
+        self.try_id_of(path).map(|id| &self.arena[id.0])
+    }
+
+    /// Returns the [ModuleId] of the submodule named `mod_name` directly beneath `parent_path`,
+    /// allocating a fresh arena slot (seeded by calling `init`) the first time this submodule is
+    /// entered. Later calls for the same path reuse the existing id rather than re-cloning the
+    /// subtree, which is the whole point of arena-allocating the module tree.
+    pub(crate) fn get_or_alloc_submodule(
+        &mut self,
+        parent_path: &Path,
+        mod_name: &Ident,
+        init: impl FnOnce() -> Module,
+    ) -> ModuleId {
+        let submod_path: PathBuf = parent_path
+            .iter()
+            .cloned()
+            .chain(std::iter::once(mod_name.clone()))
+            .collect();
+
+        if let Some(id) = self.path_to_id.get(&submod_path) {
+            return *id;
+        }
+
+        let id = self.alloc_module(init());
+        self.path_to_id.insert(submod_path, id);
+
+        let parent_id = self.id_of(parent_path);
+        self.arena[parent_id.0]
+            .submodules
+            .insert(mod_name.to_string(), id);
+
+        id
+    }
+
+    /// Resolves `symbol` in the given namespace, starting the lookup from `mod_path`.
+    ///
+    /// `namespace_kind` picks which of the module's two binding tables (see [NamespaceKind]) is
+    /// consulted, so that a name bound in both namespaces resolves to the binding the caller
+    /// actually wants.
+    ///
+    /// This doesn't take an `Engines`: a [ty::TyDecl] binding's identity (and so whether two
+    /// resolutions refer to the "same" declaration, as `==` is used for elsewhere in this module)
+    /// is determined by its declaration id, which is already resolved by the time it's bound into
+    /// a [Module]'s tables — there's nothing left for the type/decl engines to disambiguate at
+    /// lookup time.
+    pub(crate) fn resolve_symbol(
+        &self,
+        handler: &Handler,
+        mod_path: &Path,
+        symbol: &Ident,
+        namespace_kind: NamespaceKind,
+        _self_type: Option<TypeId>,
+    ) -> Result<ty::TyDecl, ErrorEmitted> {
+        // `mod_path` names a module reached via a call path's prefixes, which (unlike the
+        // current `Namespace::mod_path`) isn't guaranteed to have ever been entered — e.g. a
+        // typo'd or not-yet-visited module segment. That's an ordinary resolution failure, not a
+        // bug, so this goes through the fallible `try_module` rather than the panicking `module`.
+        let Some(target_module) = self.try_module(mod_path) else {
+            return Err(handler.emit_err(CompileError::SymbolNotFound {
+                name: symbol.clone(),
+                span: symbol.span(),
+            }));
+        };
+        target_module.resolve_symbol(handler, symbol, namespace_kind)
+    }
+
+    /// Resolves `call_path` in the given namespace, starting the lookup from `mod_path`.
+    ///
+    /// The prefixes of `call_path` name a sequence of submodules reached from `mod_path`; the
+    /// final segment is then resolved in that target module via [Root::resolve_symbol].
+    pub(crate) fn resolve_call_path(
+        &self,
+        handler: &Handler,
+        mod_path: &Path,
+        call_path: &crate::language::CallPath,
+        namespace_kind: NamespaceKind,
+        self_type: Option<TypeId>,
+    ) -> Result<ty::TyDecl, ErrorEmitted> {
+        let target_path: PathBuf = mod_path
+            .iter()
+            .chain(call_path.prefixes.iter())
+            .cloned()
+            .collect();
+        self.resolve_symbol(
+            handler,
+            &target_path,
+            &call_path.suffix,
+            namespace_kind,
+            self_type,
+        )
+    }
+}
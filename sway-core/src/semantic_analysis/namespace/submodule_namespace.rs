@@ -0,0 +1,43 @@
+use sway_error::handler::Handler;
+
+use super::{namespace::Namespace, PathBuf};
+
+/// Wraps a [Namespace] while type-checking a submodule.
+///
+/// When dropped, restores the parent `mod_path` so that type-checking can resume in the
+/// enclosing module, and, if this visit is the one finalizing unused-import checking (see
+/// `finalize_unused_imports` on [super::namespace::Namespace::enter_submodule]), emits the
+/// submodule's unused-import warnings (see [Namespace::check_unused_imports]).
+pub struct SubmoduleNamespace<'a> {
+    pub(crate) namespace: &'a mut Namespace,
+    pub(crate) parent_mod_path: PathBuf,
+    pub(crate) handler: Handler,
+    pub(crate) finalize_unused_imports: bool,
+}
+
+impl Drop for SubmoduleNamespace<'_> {
+    fn drop(&mut self) {
+        // Only the pass that's actually finished type-checking the whole tree (as opposed to an
+        // earlier collection-only pass, which hasn't resolved anything yet and would make every
+        // import look unused) should finalize; `check_unused_imports` additionally guards against
+        // this firing more than once, since the same submodule (and `ModuleId`) may still be
+        // entered again after its first finalizing exit.
+        if self.finalize_unused_imports {
+            self.namespace.check_unused_imports(&self.handler);
+        }
+        self.namespace.mod_path = std::mem::take(&mut self.parent_mod_path);
+    }
+}
+
+impl std::ops::Deref for SubmoduleNamespace<'_> {
+    type Target = Namespace;
+    fn deref(&self) -> &Self::Target {
+        self.namespace
+    }
+}
+
+impl std::ops::DerefMut for SubmoduleNamespace<'_> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        self.namespace
+    }
+}